@@ -15,6 +15,15 @@
 /// G.display();
 /// ```
 
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use union_find::UnionFind;
+
 pub struct Vertex {
     id: usize,
     pub pre: Option<usize>,
@@ -31,6 +40,7 @@ impl PartialEq for Vertex {
 
 pub struct Edge {
     endpoint: Vertex,
+    weight: f64,
 }
 
 pub struct Graph {
@@ -57,6 +67,10 @@ impl Graph {
         self.adjacency_list.add_edge(src, dst);
     }
 
+    pub fn add_edge_weighted(&mut self, src: &usize, dst: &usize, weight: f64) {
+        self.adjacency_list.add_edge_weighted(src, dst, weight);
+    }
+
     pub fn add_vertices(&mut self, n_vertices: usize) -> &mut Graph {
         for _ in 0..n_vertices {
             self.add_vertex();
@@ -107,14 +121,290 @@ impl Graph {
         None
     }
 
-    pub fn get_adjacent_vertices(&self, v: usize) -> &Vec<usize> {
+    pub fn get_adjacent_vertices(&self, v: usize) -> Vec<usize> {
         self.adjacency_list.get_adjacent_vertices(v)
     }
 
+    pub fn get_adjacent_weighted(&self, v: usize) -> &[(usize, f64)] {
+        self.adjacency_list.get_adjacent_weighted(v)
+    }
+
+    /// Dijkstra's algorithm over non-negative edge weights. Returns the total
+    /// weight of the shortest path from `src` to `dst` along with the vertex
+    /// sequence, or `None` if `dst` is unreachable from `src`.
+    pub fn shortest_path(&self, src: usize, dst: usize) -> Option<(f64, Vec<usize>)> {
+        // Sized by `next_id`, not `count_vertices()`: vertex ids are never
+        // renumbered on delete, so a deleted vertex can leave ids that exceed
+        // the live vertex count.
+        let n = self.next_id;
+        let mut dist = vec![f64::INFINITY; n];
+        let mut prev: Vec<Option<usize>> = vec![None; n];
+        let mut heap = BinaryHeap::new();
+
+        dist[src] = 0.0;
+        heap.push(Reverse(DistEntry { dist: 0.0, vertex: src }));
+
+        while let Some(Reverse(DistEntry { dist: d, vertex: u })) = heap.pop() {
+            if d > dist[u] {
+                continue;
+            }
+            if u == dst {
+                break;
+            }
+            for &(v, w) in self.get_adjacent_weighted(u) {
+                let candidate = dist[u] + w;
+                if candidate < dist[v] {
+                    dist[v] = candidate;
+                    prev[v] = Some(u);
+                    heap.push(Reverse(DistEntry { dist: candidate, vertex: v }));
+                }
+            }
+        }
+
+        if dist[dst].is_infinite() {
+            return None;
+        }
+
+        let mut path = vec![dst];
+        while let Some(p) = prev[*path.last().unwrap()] {
+            path.push(p);
+        }
+        path.reverse();
+
+        Some((dist[dst], path))
+    }
+
+    /// Groups vertices into their connected components, treating edges as
+    /// undirected for the purpose of this query.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        // Indexed by `next_id` (every id ever issued), but only live vertices
+        // are ever reported: a deleted vertex's adjacency row is emptied, so
+        // it never unions with anything and is simply skipped below.
+        let n = self.next_id;
+        let mut uf = UnionFind::new(n);
+        for u in 0..n {
+            for v in self.get_adjacent_vertices(u) {
+                uf.union(u, v);
+            }
+        }
+
+        let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+        for vertex in &self.vertices {
+            let u = vertex.id();
+            let root = uf.find(u);
+            components.entry(root).or_default().push(u);
+        }
+        components.into_values().collect()
+    }
+
+    /// Kruskal's algorithm: returns the `(src, dst, weight)` edges of a
+    /// minimum spanning tree, or of a minimum spanning forest if the graph is
+    /// disconnected.
+    pub fn minimum_spanning_tree(&self) -> Vec<(usize, usize, f64)> {
+        // `next_id` sizes the UnionFind (it must cover every id that can
+        // appear in an edge); the `n - 1` stopping point for a spanning tree
+        // is counted against the live vertices instead.
+        let n = self.next_id;
+        let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+        for u in 0..n {
+            for (v, w) in self.get_adjacent_weighted(u) {
+                edges.push((u, *v, *w));
+            }
+        }
+        edges.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+        let mut uf = UnionFind::new(n);
+        let mut mst = Vec::new();
+        let max_edges = self.count_vertices().saturating_sub(1);
+        for (src, dst, weight) in edges {
+            if mst.len() == max_edges {
+                break;
+            }
+            if !uf.connected(src, dst) {
+                uf.union(src, dst);
+                mst.push((src, dst, weight));
+            }
+        }
+        mst
+    }
+
+    /// Lazily visits vertices reachable from `start` in depth-first order.
+    pub fn dfs(&self, start: usize) -> Dfs<'_> {
+        Dfs { graph: self, stack: vec![start], visited: HashSet::new() }
+    }
+
+    /// Lazily visits vertices reachable from `start` in breadth-first order.
+    pub fn bfs(&self, start: usize) -> Bfs<'_> {
+        let mut visited = HashSet::new();
+        visited.insert(start);
+        Bfs { graph: self, queue: VecDeque::from([start]), visited }
+    }
+
+    /// Runs a depth-first search over every vertex (restarting on each
+    /// unvisited vertex so disconnected graphs are fully covered), stamping
+    /// `pre` with a monotonically increasing clock when a vertex is first
+    /// discovered and `post` when it is finished.
+    pub fn dfs_timestamps(&mut self) {
+        // Indexed by `next_id`, since neighbor ids are never renumbered on
+        // delete. `self.vertices` is looked up by id via `index_of` (not by
+        // position), since deleting a vertex shifts positions out from under
+        // ids; the map is built once so lookups stay O(1).
+        let n = self.next_id;
+        let mut visited = vec![false; n];
+        let mut clock = 0;
+        let mut index_of: Vec<Option<usize>> = vec![None; n];
+        for (i, vertex) in self.vertices.iter().enumerate() {
+            index_of[vertex.id()] = Some(i);
+        }
+        let starts: Vec<usize> = self.vertices.iter().map(|v| v.id()).collect();
+
+        for start in starts {
+            if visited[start] {
+                continue;
+            }
+
+            let mut stack: Vec<(usize, bool)> = vec![(start, false)];
+            while let Some((v, finishing)) = stack.pop() {
+                if finishing {
+                    if let Some(i) = index_of[v] {
+                        self.vertices[i].post = Some(clock);
+                    }
+                    clock += 1;
+                    continue;
+                }
+                if visited[v] {
+                    continue;
+                }
+                visited[v] = true;
+                if let Some(i) = index_of[v] {
+                    self.vertices[i].pre = Some(clock);
+                }
+                clock += 1;
+                stack.push((v, true));
+                for u in self.get_adjacent_vertices(v).into_iter().rev() {
+                    if !visited[u] {
+                        stack.push((u, false));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Topologically sorts the graph by reversing DFS finish order.
+    /// Returns `None` if a back edge reveals a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        // Indexed by `next_id`, since neighbor ids are never renumbered on
+        // delete; only live vertices are used as traversal starts.
+        let n = self.next_id;
+        let mut visited = vec![false; n];
+        let mut on_stack = vec![false; n];
+        let mut finish_order = Vec::with_capacity(self.count_vertices());
+        let starts: Vec<usize> = self.vertices.iter().map(|v| v.id()).collect();
+
+        for start in starts {
+            if visited[start] {
+                continue;
+            }
+
+            let mut stack: Vec<(usize, bool)> = vec![(start, false)];
+            while let Some((v, finishing)) = stack.pop() {
+                if finishing {
+                    on_stack[v] = false;
+                    finish_order.push(v);
+                    continue;
+                }
+                if visited[v] {
+                    continue;
+                }
+                visited[v] = true;
+                on_stack[v] = true;
+                stack.push((v, true));
+                for u in self.get_adjacent_vertices(v) {
+                    if on_stack[u] {
+                        return None;
+                    }
+                    if !visited[u] {
+                        stack.push((u, false));
+                    }
+                }
+            }
+        }
+
+        finish_order.reverse();
+        Some(finish_order)
+    }
+
+}
+
+pub struct Dfs<'a> {
+    graph: &'a Graph,
+    stack: Vec<usize>,
+    visited: HashSet<usize>,
+}
+
+impl<'a> Iterator for Dfs<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(v) = self.stack.pop() {
+            if self.visited.insert(v) {
+                for u in self.graph.get_adjacent_vertices(v).into_iter().rev() {
+                    if !self.visited.contains(&u) {
+                        self.stack.push(u);
+                    }
+                }
+                return Some(v);
+            }
+        }
+        None
+    }
+}
+
+pub struct Bfs<'a> {
+    graph: &'a Graph,
+    queue: VecDeque<usize>,
+    visited: HashSet<usize>,
+}
+
+impl<'a> Iterator for Bfs<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let v = self.queue.pop_front()?;
+        for u in self.graph.get_adjacent_vertices(v) {
+            if self.visited.insert(u) {
+                self.queue.push_back(u);
+            }
+        }
+        Some(v)
+    }
+}
+
+#[derive(PartialEq)]
+struct DistEntry {
+    dist: f64,
+    vertex: usize,
+}
+
+impl Eq for DistEntry {}
+
+impl Ord for DistEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist
+            .partial_cmp(&other.dist)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| self.vertex.cmp(&other.vertex))
+    }
+}
+
+impl PartialOrd for DistEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 pub struct AdjacencyList {
-    vertices: Vec<Vec<usize>>,
+    vertices: Vec<Vec<(usize, f64)>>,
 }
 
 impl AdjacencyList {
@@ -124,32 +414,158 @@ impl AdjacencyList {
     }
 
     pub fn add_edge(&mut self, src: &usize, dst: &usize) {
-        self.vertices[*src].push(*dst);
+        self.add_edge_weighted(src, dst, 1.0);
+    }
+
+    pub fn add_edge_weighted(&mut self, src: &usize, dst: &usize, weight: f64) {
+        self.vertices[*src].push((*dst, weight));
     }
 
     pub fn delete_vertex(&mut self, v: &usize) {
         self.vertices[*v] = vec![];
+        for row in &mut self.vertices {
+            row.retain(|(dst, _)| dst != v);
+        }
     }
 
-    pub fn get_adjacent_vertices(&self, src: usize) -> &Vec<usize> {
+    pub fn get_adjacent_vertices(&self, src: usize) -> Vec<usize> {
+        self.vertices[src].iter().map(|(dst, _)| *dst).collect()
+    }
+
+    pub fn get_adjacent_weighted(&self, src: usize) -> &[(usize, f64)] {
         &self.vertices[src]
     }
 
 }
 
-// FUTURE:
-// struct UndirectedGraph {
-//     graph: Graph,
-// }
+/// Disjoint-set-union, used to answer connectivity queries and to drive
+/// Kruskal's minimum-spanning-tree algorithm.
+pub mod union_find {
+
+    pub struct UnionFind {
+        parent: Vec<usize>,
+        rank: Vec<usize>,
+    }
 
-// impl UndirectedGraph {
+    impl UnionFind {
 
-//     pub fn add_edge(&mut self, src: &usize, dst: &usize) {
-//         self.graph.add_edge(src, dst);
-//         self.graph.add_edge(dst, src);
-//     }
+        pub fn new(n: usize) -> UnionFind {
+            UnionFind { parent: (0..n).collect(), rank: vec![0; n] }
+        }
 
-// }
+        pub fn find(&mut self, x: usize) -> usize {
+            if self.parent[x] != x {
+                self.parent[x] = self.find(self.parent[x]);
+            }
+            self.parent[x]
+        }
+
+        pub fn union(&mut self, a: usize, b: usize) {
+            let root_a = self.find(a);
+            let root_b = self.find(b);
+            if root_a == root_b {
+                return;
+            }
+            match self.rank[root_a].cmp(&self.rank[root_b]) {
+                std::cmp::Ordering::Less => self.parent[root_a] = root_b,
+                std::cmp::Ordering::Greater => self.parent[root_b] = root_a,
+                std::cmp::Ordering::Equal => {
+                    self.parent[root_b] = root_a;
+                    self.rank[root_a] += 1;
+                }
+            }
+        }
+
+        pub fn connected(&mut self, a: usize, b: usize) -> bool {
+            self.find(a) == self.find(b)
+        }
+
+    }
+
+}
+
+/// An undirected graph, implemented as a `Graph` whose edges are always
+/// inserted in both directions.
+pub struct UndirectedGraph {
+    graph: Graph,
+}
+
+impl UndirectedGraph {
+
+    pub fn new() -> UndirectedGraph {
+        UndirectedGraph { graph: Graph::new() }
+    }
+
+    pub fn add_vertex(&mut self) {
+        self.graph.add_vertex();
+    }
+
+    pub fn add_vertices(&mut self, n_vertices: usize) -> &mut UndirectedGraph {
+        self.graph.add_vertices(n_vertices);
+        self
+    }
+
+    pub fn add_edge(&mut self, src: &usize, dst: &usize) {
+        self.graph.add_edge(src, dst);
+        self.graph.add_edge(dst, src);
+    }
+
+    pub fn add_edge_weighted(&mut self, src: &usize, dst: &usize, weight: f64) {
+        self.graph.add_edge_weighted(src, dst, weight);
+        self.graph.add_edge_weighted(dst, src, weight);
+    }
+
+    pub fn count_vertices(&self) -> usize {
+        self.graph.count_vertices()
+    }
+
+    /// The logical (undirected) edge count, i.e. half the underlying
+    /// directed edge count.
+    pub fn count_edges(&self) -> usize {
+        self.graph.count_edges() / 2
+    }
+
+    pub fn delete_vertex(&mut self, v: &usize) {
+        self.graph.delete_vertex(v);
+    }
+
+    pub fn display(&self) {
+        self.graph.display();
+    }
+
+    pub fn get_vertex(&self, id: &usize) -> Option<&Vertex> {
+        self.graph.get_vertex(id)
+    }
+
+    pub fn get_adjacent_vertices(&self, v: usize) -> Vec<usize> {
+        self.graph.get_adjacent_vertices(v)
+    }
+
+    pub fn get_adjacent_weighted(&self, v: usize) -> &[(usize, f64)] {
+        self.graph.get_adjacent_weighted(v)
+    }
+
+    pub fn shortest_path(&self, src: usize, dst: usize) -> Option<(f64, Vec<usize>)> {
+        self.graph.shortest_path(src, dst)
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        self.graph.connected_components()
+    }
+
+    pub fn minimum_spanning_tree(&self) -> Vec<(usize, usize, f64)> {
+        self.graph.minimum_spanning_tree()
+    }
+
+    pub fn dfs(&self, start: usize) -> Dfs<'_> {
+        self.graph.dfs(start)
+    }
+
+    pub fn bfs(&self, start: usize) -> Bfs<'_> {
+        self.graph.bfs(start)
+    }
+
+}
 
 #[cfg(test)]
 mod tests {
@@ -209,7 +625,265 @@ mod tests {
         let v = G.get_vertex(&0).unwrap();
         let truth: Vec<usize> = vec![0, 1, 2, 3, 4];
         let av = G.get_adjacent_vertices(v.id());
-        assert_eq!(*av, truth);
+        assert_eq!(av, truth);
+    }
+
+    #[test]
+    fn test_weighted_edges() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge(&0, &1);
+        G.add_edge_weighted(&0, &2, 2.5);
+        assert_eq!(G.count_edges(), 2);
+        assert_eq!(G.get_adjacent_vertices(0), vec![1, 2]);
+        assert_eq!(G.get_adjacent_weighted(0), &[(1, 1.0), (2, 2.5)]);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut G = Graph::new();
+        G.add_vertices(4);
+        G.add_edge_weighted(&0, &1, 1.0);
+        G.add_edge_weighted(&1, &2, 2.0);
+        G.add_edge_weighted(&0, &2, 5.0);
+        G.add_edge_weighted(&2, &3, 1.0);
+        let (cost, path) = G.shortest_path(0, 3).unwrap();
+        assert_eq!(cost, 4.0);
+        assert_eq!(path, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let mut G = Graph::new();
+        G.add_vertices(2);
+        assert_eq!(G.shortest_path(0, 1), None);
+    }
+
+    #[test]
+    fn test_shortest_path_after_delete_vertex() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge_weighted(&1, &2, 5.0);
+        G.delete_vertex(&0);
+        assert_eq!(G.shortest_path(1, 2), Some((5.0, vec![1, 2])));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut G = Graph::new();
+        G.add_vertices(5);
+        G.add_edge(&0, &1);
+        G.add_edge(&1, &2);
+        G.add_edge(&3, &4);
+        let mut components = G.connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn test_connected_components_after_delete_vertex() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge(&1, &2);
+        G.delete_vertex(&0);
+        let mut components = G.connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let mut G = Graph::new();
+        G.add_vertices(4);
+        G.add_edge_weighted(&0, &1, 1.0);
+        G.add_edge_weighted(&1, &0, 1.0);
+        G.add_edge_weighted(&1, &2, 2.0);
+        G.add_edge_weighted(&2, &1, 2.0);
+        G.add_edge_weighted(&0, &2, 5.0);
+        G.add_edge_weighted(&2, &0, 5.0);
+        G.add_edge_weighted(&2, &3, 1.0);
+        G.add_edge_weighted(&3, &2, 1.0);
+        let mst = G.minimum_spanning_tree();
+        let total_weight: f64 = mst.iter().map(|(_, _, w)| w).sum();
+        assert_eq!(mst.len(), 3);
+        assert_eq!(total_weight, 4.0);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_after_delete_vertex() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge_weighted(&1, &2, 5.0);
+        G.add_edge_weighted(&2, &1, 5.0);
+        G.delete_vertex(&0);
+        let mst = G.minimum_spanning_tree();
+        assert_eq!(mst, vec![(1, 2, 5.0)]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let mut G = Graph::new();
+        G.add_vertices(4);
+        G.add_edge(&0, &1);
+        G.add_edge(&0, &2);
+        G.add_edge(&1, &3);
+        let visited: Vec<usize> = G.dfs(0).collect();
+        assert_eq!(visited, vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let mut G = Graph::new();
+        G.add_vertices(4);
+        G.add_edge(&0, &1);
+        G.add_edge(&0, &2);
+        G.add_edge(&1, &3);
+        let visited: Vec<usize> = G.bfs(0).collect();
+        assert_eq!(visited, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_dfs_short_circuits() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.fully_connect();
+        let first_two: Vec<usize> = G.dfs(0).take(2).collect();
+        assert_eq!(first_two.len(), 2);
+    }
+
+    #[test]
+    fn test_dfs_timestamps() {
+        let mut G = Graph::new();
+        G.add_vertices(5);
+        G.add_edge(&0, &1);
+        G.add_edge(&1, &2);
+        G.add_edge(&3, &4);
+        G.dfs_timestamps();
+        for v in &G.vertices {
+            assert!(v.pre.is_some());
+            assert!(v.post.is_some());
+            assert!(v.pre.unwrap() < v.post.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_dfs_timestamps_after_delete_vertex() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge(&1, &2);
+        G.delete_vertex(&0);
+        G.dfs_timestamps();
+        for v in &G.vertices {
+            assert!(v.pre.is_some());
+            assert!(v.post.is_some());
+            assert!(v.pre.unwrap() < v.post.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut G = Graph::new();
+        G.add_vertices(4);
+        G.add_edge(&0, &1);
+        G.add_edge(&0, &2);
+        G.add_edge(&1, &3);
+        G.add_edge(&2, &3);
+        let order = G.topological_sort().unwrap();
+        let position = |v: usize| order.iter().position(|&x| x == v).unwrap();
+        assert!(position(0) < position(1));
+        assert!(position(0) < position(2));
+        assert!(position(1) < position(3));
+        assert!(position(2) < position(3));
+    }
+
+    #[test]
+    fn test_topological_sort_detects_cycle() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge(&0, &1);
+        G.add_edge(&1, &2);
+        G.add_edge(&2, &0);
+        assert_eq!(G.topological_sort(), None);
+    }
+
+    #[test]
+    fn test_topological_sort_after_delete_vertex() {
+        let mut G = Graph::new();
+        G.add_vertices(3);
+        G.add_edge(&1, &2);
+        G.delete_vertex(&0);
+        let order = G.topological_sort().unwrap();
+        let position = |v: usize| order.iter().position(|&x| x == v).unwrap();
+        assert_eq!(order.len(), 2);
+        assert!(position(1) < position(2));
+    }
+
+    #[test]
+    fn test_undirected_graph_mirrors_edges() {
+        let mut G = UndirectedGraph::new();
+        G.add_vertices(3);
+        G.add_edge(&0, &1);
+        assert_eq!(G.count_edges(), 1);
+        assert_eq!(G.get_adjacent_vertices(0), vec![1]);
+        assert_eq!(G.get_adjacent_vertices(1), vec![0]);
+    }
+
+    #[test]
+    fn test_undirected_graph_delete_vertex_clears_neighbors() {
+        let mut G = UndirectedGraph::new();
+        G.add_vertices(3);
+        G.add_edge(&0, &1);
+        G.add_edge(&1, &2);
+        G.delete_vertex(&1);
+        assert_eq!(G.get_adjacent_vertices(0), Vec::<usize>::new());
+        assert_eq!(G.get_adjacent_vertices(2), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_directed_delete_vertex_clears_inbound_edges() {
+        let mut G = Graph::new();
+        G.add_vertices(2);
+        G.add_edge(&0, &1);
+        G.delete_vertex(&1);
+        assert_eq!(G.get_adjacent_vertices(0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_undirected_graph_mst() {
+        let mut G = UndirectedGraph::new();
+        G.add_vertices(3);
+        G.add_edge_weighted(&0, &1, 1.0);
+        G.add_edge_weighted(&1, &2, 2.0);
+        G.add_edge_weighted(&0, &2, 5.0);
+        let mst = G.minimum_spanning_tree();
+        let total_weight: f64 = mst.iter().map(|(_, _, w)| w).sum();
+        assert_eq!(mst.len(), 2);
+        assert_eq!(total_weight, 3.0);
+    }
+
+    #[test]
+    fn test_undirected_graph_queries_after_delete_vertex() {
+        let mut G = UndirectedGraph::new();
+        G.add_vertices(3);
+        G.add_edge_weighted(&1, &2, 5.0);
+        G.delete_vertex(&0);
+
+        assert_eq!(G.shortest_path(1, 2), Some((5.0, vec![1, 2])));
+
+        let mut components = G.connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2]]);
+
+        assert_eq!(G.minimum_spanning_tree(), vec![(1, 2, 5.0)]);
     }
 
 